@@ -0,0 +1,108 @@
+// concurrency in rust: a hand-built spinlock
+
+// A minimal SpinLock<T> built from AtomicBool + UnsafeCell<T>, the primitives a real Mutex
+// is built on.
+use std::cell::UnsafeCell;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+struct SpinLock<T> {
+    locked: AtomicBool,
+    data: UnsafeCell<T>,
+}
+
+// SAFETY: SpinLock<T> only ever exposes `&mut T` through SpinLockGuard while `locked` is true,
+// and compare_exchange_weak guarantees only one thread can flip it false -> true at a time.
+unsafe impl<T> Sync for SpinLock<T> where T: Send {}
+
+impl<T> SpinLock<T> {
+    fn new(data: T) -> Self {
+        SpinLock {
+            locked: AtomicBool::new(false),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    fn lock(&self) -> SpinLockGuard<'_, T> {
+        // Spin until we're the thread that flips `locked` from false to true.
+        //
+        // Ordering::Acquire pairs with the Release store in the guard's Drop: it guarantees that
+        // every write the PREVIOUS lock holder made inside the critical section is visible to us
+        // once we observe `locked == false -> true` here.
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            std::hint::spin_loop();
+        }
+
+        // --- broken variant (commented out) ---
+        // while self
+        //     .locked
+        //     .compare_exchange_weak(false, true, Ordering::Relaxed, Ordering::Relaxed)
+        //     .is_err()
+        // {}
+        // With Ordering::Relaxed on both sides there is no happens-before edge between the
+        // unlocking thread's writes and this thread's subsequent reads. The CPU or compiler is
+        // then free to reorder the protected writes so they appear to happen AFTER another
+        // thread has already acquired the lock, silently corrupting `data` even though the
+        // bool itself toggles correctly. Acquire/Release is what makes the critical section's
+        // writes actually visible in order.
+
+        SpinLockGuard { lock: self }
+    }
+}
+
+struct SpinLockGuard<'a, T> {
+    lock: &'a SpinLock<T>,
+}
+
+impl<'a, T> Deref for SpinLockGuard<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        // SAFETY: holding the guard means `locked` is true and we are the sole holder.
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<'a, T> DerefMut for SpinLockGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: same as above.
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<'a, T> Drop for SpinLockGuard<'a, T> {
+    fn drop(&mut self) {
+        // Ordering::Release pairs with the Acquire in lock(): it publishes every write made
+        // through this guard before the lock is seen as free.
+        self.lock.locked.store(false, Ordering::Release);
+    }
+}
+
+fn main() {
+    // Same 10-thread increment loop as the std Mutex example
+    let counter = Arc::new(SpinLock::new(0));
+
+    let mut handles = vec![];
+
+    for _ in 0..10 {
+        let counter = Arc::clone(&counter);
+
+        let handle = thread::spawn(move || {
+            let mut num = counter.lock();
+            *num += 1;
+            *num *= 2;
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    println!("Result: {}", *counter.lock());
+}
@@ -0,0 +1,39 @@
+// concurrency in rust: fork-join parallel reduce
+
+// thread::scope lets spawned threads borrow stack data directly - no Arc needed - because the
+// scope blocks until they all finish.
+use std::thread;
+
+const SEQUENTIAL_THRESHOLD: usize = 10_000;
+
+fn parallel_sum(data: &[i64]) -> i64 {
+    if data.len() <= SEQUENTIAL_THRESHOLD {
+        return data.iter().sum();
+    }
+
+    let mid = data.len() / 2;
+    let (left, right) = data.split_at(mid);
+
+    thread::scope(|s| {
+        // The left half is handed to a scoped thread; `left` only needs to outlive the scope,
+        // not 'static, because thread::scope blocks until every spawned thread has joined.
+        let left_handle = s.spawn(|| parallel_sum(left));
+
+        // The current thread recurses on the right half while the spawned thread works the left.
+        let right_sum = parallel_sum(right);
+
+        let left_sum = left_handle.join().unwrap();
+        left_sum + right_sum
+    })
+}
+
+fn main() {
+    let data: Vec<i64> = (1..=1_000_000).collect();
+
+    let sequential: i64 = data.iter().sum();
+    let parallel = parallel_sum(&data);
+
+    println!("sequential sum: {sequential}");
+    println!("parallel sum:   {parallel}");
+    assert_eq!(sequential, parallel);
+}
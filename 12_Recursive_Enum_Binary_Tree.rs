@@ -0,0 +1,50 @@
+// Rust's pattern matching is powerful: recursive data structures
+
+// An enum can't directly contain itself - Box<T> gives it a known, fixed size so recursive
+// variants are possible.
+enum BinaryTree {
+    Leaf(i32),
+    Node(Box<BinaryTree>, i32, Box<BinaryTree>),
+}
+
+fn sum(tree: &BinaryTree) -> i32 {
+    match tree {
+        BinaryTree::Leaf(value) => *value,
+        BinaryTree::Node(left, value, right) => sum(left) + value + sum(right),
+    }
+}
+
+fn depth(tree: &BinaryTree) -> usize {
+    match tree {
+        BinaryTree::Leaf(_) => 1,
+        BinaryTree::Node(left, _, right) => 1 + depth(left).max(depth(right)),
+    }
+}
+
+fn sample_tree() -> BinaryTree {
+    //         4
+    //       /   \
+    //      2     5
+    //     / \   / \
+    //    1   3 5   6
+    BinaryTree::Node(
+        Box::new(BinaryTree::Node(
+            Box::new(BinaryTree::Leaf(1)),
+            2,
+            Box::new(BinaryTree::Leaf(3)),
+        )),
+        4,
+        Box::new(BinaryTree::Node(
+            Box::new(BinaryTree::Leaf(5)),
+            5,
+            Box::new(BinaryTree::Leaf(6)),
+        )),
+    )
+}
+
+fn main() {
+    let tree = sample_tree();
+
+    println!("sum: {}", sum(&tree));
+    println!("depth: {}", depth(&tree));
+}
@@ -0,0 +1,35 @@
+// Calling into C through extern blocks
+
+// Rust &str is a (ptr, len) pair of UTF-8 bytes; C strings are just a pointer, NUL-terminated.
+// CString bridges the two so a C function can safely walk the bytes.
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int};
+
+extern "C" {
+    fn abs(n: c_int) -> c_int;
+    fn strlen(s: *const c_char) -> usize;
+}
+
+// Safe wrapper: ordinary signature in, ordinary value out. The unsafe call is hidden inside.
+fn c_abs(n: i32) -> i32 {
+    // SAFETY: abs() is a pure function that reads nothing but its argument.
+    unsafe { abs(n) }
+}
+
+// Safe wrapper around strlen, hiding the CString conversion and the raw pointer.
+fn c_strlen(s: &str) -> usize {
+    // CString::new fails only if `s` contains an interior NUL byte, which a C string can't
+    // represent.
+    let c_string = CString::new(s).expect("string contains an interior NUL byte");
+
+    // SAFETY: c_string owns its buffer and outlives this call, and it is guaranteed
+    // NUL-terminated, which is exactly what strlen requires.
+    unsafe { strlen(c_string.as_ptr()) }
+}
+
+fn main() {
+    println!("abs(-42) = {}", c_abs(-42));
+
+    let greeting = "Hello, FFI!";
+    println!("strlen({:?}) = {}", greeting, c_strlen(greeting));
+}
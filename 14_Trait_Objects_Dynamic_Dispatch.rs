@@ -0,0 +1,70 @@
+//  Trait objects: dynamic dispatch over Summary
+
+// Box<dyn Summary> erases the concrete type behind a fixed-size pointer plus a vtable, so
+// a Vec can hold a mix of Tweet and NewsArticle.
+trait Summary {
+    fn summarize(&self) -> String;
+
+    fn default_summary(&self) -> String {
+        String::from("(Read more...)")
+    }
+}
+
+struct NewsArticle {
+    headline: String,
+    location: String,
+    author: String,
+    content: String,
+}
+
+impl Summary for NewsArticle {
+    fn summarize(&self) -> String {
+        format!(
+            "{}, by {} ({}) \ncontent: {}",
+            self.headline, self.author, self.location, self.content
+        )
+    }
+}
+
+struct Tweet {
+    username: String,
+    content: String,
+}
+
+impl Summary for Tweet {
+    fn summarize(&self) -> String {
+        format!("{}: {}", self.username, self.content)
+    }
+}
+
+// Iterator combinators work the same over trait objects as over any other type: `map` doesn't
+// care that each `Box<dyn Summary>` might hold a different concrete type underneath.
+fn notify_all(items: &[Box<dyn Summary>]) -> Vec<String> {
+    items.iter().map(|item| item.summarize()).collect::<Vec<_>>()
+}
+
+fn main() {
+    let items: Vec<Box<dyn Summary>> = vec![
+        Box::new(Tweet {
+            username: String::from("Mr. Ignius"),
+            content: String::from("I work hard by smartness!"),
+        }),
+        Box::new(NewsArticle {
+            headline: String::from("Mr.Ignius done p2p"),
+            location: String::from("127.0.0.1"),
+            author: String::from("Mr. Gen"),
+            content: String::from("Revolution of Whole Networking System!!"),
+        }),
+    ];
+
+    // Each call below resolves summarize()/default_summary() through the item's vtable -
+    // dynamic dispatch, decided at runtime rather than baked in at compile time.
+    for item in items.iter() {
+        println!("Breaking news! {}", item.summarize());
+        println!("{}", item.default_summary());
+    }
+
+    for headline in notify_all(&items) {
+        println!("notify_all: {headline}");
+    }
+}
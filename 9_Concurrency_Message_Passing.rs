@@ -0,0 +1,70 @@
+// concurrency in rust: message passing
+
+// mpsc: multi-producer, single-consumer channel. Threads talk by sending values instead of
+// sharing memory.
+use std::sync::mpsc;
+use std::thread;
+
+// A unit of work handed to a worker thread
+enum Job {
+    Compute(u64),
+    Shutdown,
+}
+
+fn main() {
+    // job_tx feeds work to the workers, result_rx collects what they produce
+    let (job_tx, job_rx) = mpsc::channel::<Job>();
+    let (result_tx, result_rx) = mpsc::channel::<u64>();
+
+    // mpsc::Receiver can't be shared between threads directly, so we wrap it the usual way:
+    // one receiving end guarded by a Mutex, behind an Arc for shared ownership.
+    let job_rx = std::sync::Arc::new(std::sync::Mutex::new(job_rx));
+
+    let mut handles = vec![];
+
+    for id in 0..4 {
+        let job_rx = std::sync::Arc::clone(&job_rx);
+        let result_tx = result_tx.clone();
+
+        let handle = thread::spawn(move || loop {
+            // Lock only long enough to pull the next job off the channel
+            let job = job_rx.lock().unwrap().recv();
+
+            match job {
+                Ok(Job::Compute(n)) => {
+                    let squared = n * n;
+                    println!("worker {id} computed {n}^2 = {squared}");
+                    result_tx.send(squared).unwrap();
+                }
+                Ok(Job::Shutdown) | Err(_) => break,
+            }
+        });
+        handles.push(handle);
+    }
+
+    // Drop our own result_tx clone; each worker still holds one, so the channel stays open
+    // until every worker finishes.
+    drop(result_tx);
+
+    for n in 0..20u64 {
+        job_tx.send(Job::Compute(n)).unwrap();
+    }
+    for _ in 0..4 {
+        job_tx.send(Job::Shutdown).unwrap();
+    }
+
+    // Dropping job_tx isn't strictly required here since we already sent a Shutdown per worker,
+    // but it documents the other way a consumer loop ends: once every sender is dropped, recv()
+    // starts returning Err and a `for msg in rx` loop exits on its own.
+    drop(job_tx);
+
+    // Single consumer loop aggregates results as they arrive. This terminates cleanly once every
+    // worker has exited and dropped its result_tx clone, closing the channel.
+    let total: u64 = result_rx.iter().sum();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    println!("Total of squares: {total}");
+}